@@ -0,0 +1,41 @@
+//! Embedding `.ftl` assets directly into the binary.
+//!
+//! Filesystem loading (see [`bundle`](crate::bundle)) doesn't work for single-binary
+//! deployments, where translations need to ship inside the executable rather than next to
+//! it. [`I18nAssets`] abstracts over "a set of named byte blobs, grouped by locale", so the
+//! bundle-loading subsystem can treat embedded and on-disk translations the same way.
+
+use std::borrow::Cow;
+
+use fluent_templates::LanguageIdentifier;
+
+/// A source of embedded `.ftl` assets, grouped by locale.
+///
+/// Paths are expected to be of the form `<locale>/<file>.ftl`, mirroring the on-disk
+/// layout used by [`bundle::load_bundle`](crate::bundle::load_bundle).
+pub trait I18nAssets {
+    /// Returns the raw bytes of the asset at `path`, or [`None`] if it doesn't exist.
+    fn get_file(&self, path: &str) -> Option<Cow<'static, [u8]>>;
+
+    /// Returns the paths of every asset available for `locale`.
+    fn files_for_locale(&self, locale: &LanguageIdentifier) -> Vec<String>;
+}
+
+/// Blanket implementation for any type deriving [`rust_embed::RustEmbed`], so embedded
+/// assets can be passed anywhere an `&dyn I18nAssets` is expected.
+///
+/// Requires the `rust-embed` feature.
+#[cfg(feature = "rust-embed")]
+impl<T: rust_embed::RustEmbed> I18nAssets for T {
+    fn get_file(&self, path: &str) -> Option<Cow<'static, [u8]>> {
+        T::get(path).map(|file| file.data)
+    }
+
+    fn files_for_locale(&self, locale: &LanguageIdentifier) -> Vec<String> {
+        let prefix = format!("{locale}/");
+        T::iter()
+            .filter(|path| path.starts_with(&prefix))
+            .map(|path| path.into_owned())
+            .collect()
+    }
+}
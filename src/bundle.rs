@@ -0,0 +1,262 @@
+//! Multi-directory `.ftl` bundle loading.
+//!
+//! This complements the compile-time [`i18n!`](crate::i18n) macro and the runtime
+//! [`Localizer`](crate::Localizer) with a lower-level building block: loading and merging
+//! every `.ftl` file for a single locale, searched across an ordered list of candidate
+//! directories, into one [`FluentBundle`]. [`lookup_checked`] goes one step further,
+//! formatting a message directly against such a bundle so Fluent format errors can be
+//! told apart from a missing key — something not possible through
+//! [`fluent_templates::Loader`] (used by [`t!`](crate::t)/[`try_t!`](crate::try_t)), which
+//! only ever returns a sentinel string.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use fluent_templates::{
+    fluent_bundle::{FluentArgs, FluentBundle, FluentResource, FluentValue},
+    LanguageIdentifier,
+};
+
+use crate::{assets::I18nAssets, Error};
+
+/// Loads and merges every `.ftl` file for `locale` found under `override_dir` (if given)
+/// and `default_dir`, into a single [`FluentBundle`].
+///
+/// `override_dir` is meant for an app-provided custom asset layout: if `locale`'s directory
+/// is missing there, this is a soft miss — logged via [`log::warn!`] and otherwise ignored,
+/// since callers aren't expected to provide an override for every locale. A missing
+/// directory under `default_dir`, however, is a hard [`Error::MissingLocaleDir`], since
+/// that's the asset layout the crate itself expects to exist.
+///
+/// # Errors
+///
+/// Returns an error if:
+///
+/// - `locale`'s directory is missing under `default_dir`,
+/// - a locales directory cannot be read,
+/// - a `.ftl` file fails to parse,
+/// - or a parsed resource fails to be added to the bundle (e.g. a duplicate message
+///   identifier).
+pub fn load_bundle(
+    locale: &LanguageIdentifier,
+    override_dir: Option<&Path>,
+    default_dir: &Path,
+) -> Result<FluentBundle<FluentResource>, Error> {
+    let mut bundle = FluentBundle::new(vec![locale.clone()]);
+
+    if let Some(override_dir) = override_dir {
+        let dir = override_dir.join(locale.to_string());
+        if dir.is_dir() {
+            load_dir_into(&mut bundle, &dir)?;
+        } else {
+            log::warn!(
+                "no override locale directory for `{locale}` at {}; skipping",
+                dir.display()
+            );
+        }
+    }
+
+    let dir = default_dir.join(locale.to_string());
+    if !dir.is_dir() {
+        return Err(Error::MissingLocaleDir {
+            locale: locale.clone(),
+        });
+    }
+    load_dir_into(&mut bundle, &dir)?;
+
+    Ok(bundle)
+}
+
+/// Loads and merges every `.ftl` asset [`I18nAssets::files_for_locale`] reports for
+/// `locale` into a single [`FluentBundle`], the embedded-asset equivalent of
+/// [`load_bundle`].
+///
+/// # Errors
+///
+/// Returns an error if an asset reported by [`files_for_locale`](I18nAssets::files_for_locale)
+/// is missing when fetched, is not valid UTF-8, fails to parse, or fails to be added to the
+/// bundle.
+pub fn load_bundle_from_assets(
+    locale: &LanguageIdentifier,
+    assets: &dyn I18nAssets,
+) -> Result<FluentBundle<FluentResource>, Error> {
+    let mut bundle = FluentBundle::new(vec![locale.clone()]);
+
+    for path in assets.files_for_locale(locale) {
+        let bytes = assets
+            .get_file(&path)
+            .ok_or_else(|| Error::AssetNotFound { path: path.clone() })?;
+        let contents = String::from_utf8(bytes.into_owned()).map_err(|source| Error::ParseFtl {
+            path: PathBuf::from(&path),
+            source: source.to_string(),
+        })?;
+
+        let resource = FluentResource::try_new(contents).map_err(|(_, errors)| Error::ParseFtl {
+            path: PathBuf::from(&path),
+            source: format!("{errors:?}"),
+        })?;
+
+        bundle
+            .add_resource(resource)
+            .map_err(|_| Error::AddResource {
+                path: PathBuf::from(&path),
+            })?;
+    }
+
+    Ok(bundle)
+}
+
+/// Reads every `.ftl` file directly under `dir` and adds it as a resource to `bundle`.
+fn load_dir_into(bundle: &mut FluentBundle<FluentResource>, dir: &Path) -> Result<(), Error> {
+    let entries = fs::read_dir(dir).map_err(|source| Error::ReadLocalesDir {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+
+    for entry in entries {
+        let entry = entry.map_err(|source| Error::ReadLocalesDir {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("ftl") {
+            continue;
+        }
+
+        let contents = fs::read_to_string(&path).map_err(|source| Error::ReadLocalesDir {
+            path: path.clone(),
+            source,
+        })?;
+        let resource = FluentResource::try_new(contents).map_err(|(_, errors)| Error::ParseFtl {
+            path: path.clone(),
+            source: format!("{errors:?}"),
+        })?;
+
+        bundle
+            .add_resource(resource)
+            .map_err(|_| Error::AddResource { path: path.clone() })?;
+    }
+
+    Ok(())
+}
+
+/// Looks up `key` in `bundle` and formats it, the same way
+/// [`Loader::lookup_with_args`](fluent_templates::Loader::lookup_with_args) would, except
+/// that this goes straight to the [`FluentBundle`] API instead of going through
+/// [`Loader`](fluent_templates::Loader), so Fluent's own formatting errors are surfaced
+/// instead of being swallowed.
+///
+/// As with [`t!`](crate::t), `key` may be a dotted `message.attribute` form to look up an
+/// attribute pattern instead of the message's own value.
+///
+/// # Errors
+///
+/// Returns [`Error::MessageNotFound`] if `key` (or, for the dotted form, the named
+/// attribute) doesn't exist in `bundle`, or [`Error::FormatErrors`] if the message exists
+/// but one or more placeables failed to format (e.g. referencing a missing argument).
+pub fn lookup_checked(
+    bundle: &FluentBundle<FluentResource>,
+    key: &str,
+    args: Option<&HashMap<Cow<str>, FluentValue>>,
+) -> Result<String, Error> {
+    let not_found = || Error::MessageNotFound {
+        key: key.to_string(),
+        tried: bundle.locales.iter().map(ToString::to_string).collect(),
+    };
+
+    let (message_id, attribute) = match key.split_once('.') {
+        Some((id, attr)) => (id, Some(attr)),
+        None => (key, None),
+    };
+
+    let message = bundle.get_message(message_id).ok_or_else(not_found)?;
+
+    let pattern = match attribute {
+        Some(attr) => message
+            .get_attribute(attr)
+            .map(|a| a.value())
+            .ok_or_else(not_found)?,
+        None => message.value().ok_or_else(not_found)?,
+    };
+
+    let fluent_args = args.map(|args| {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(name.clone(), value.clone());
+        }
+        fluent_args
+    });
+
+    let mut errors = Vec::new();
+    let formatted = bundle
+        .format_pattern(pattern, fluent_args.as_ref(), &mut errors)
+        .into_owned();
+
+    if errors.is_empty() {
+        Ok(formatted)
+    } else {
+        Err(Error::FormatErrors {
+            key: key.to_string(),
+            errors: errors.iter().map(ToString::to_string).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unic_langid::langid;
+
+    use super::*;
+
+    fn test_bundle(ftl: &str) -> FluentBundle<FluentResource> {
+        let mut bundle = FluentBundle::new(vec![langid!("en-US")]);
+        bundle.set_use_isolating(false);
+        bundle
+            .add_resource(FluentResource::try_new(ftl.to_string()).expect("valid ftl"))
+            .expect("no duplicate message ids");
+        bundle
+    }
+
+    /// Ensures that [`lookup_checked`] formats both a top-level message and a dotted
+    /// `message.attribute` pattern, the same forms [`t!`](crate::t) supports.
+    #[test]
+    fn test_lookup_checked_resolves_message_and_attribute() -> testresult::TestResult<()> {
+        let bundle = test_bundle(
+            "greeting = Hello, { $name }!\nlogin-button = Log in\n    .aria-label = Log in with { $service }\n",
+        );
+
+        let mut args = HashMap::new();
+        args.insert(Cow::Borrowed("name"), FluentValue::from("Orhun"));
+        assert_eq!(lookup_checked(&bundle, "greeting", Some(&args))?, "Hello, Orhun!");
+
+        let mut attr_args = HashMap::new();
+        attr_args.insert(Cow::Borrowed("service"), FluentValue::from("GitHub"));
+        assert_eq!(
+            lookup_checked(&bundle, "login-button.aria-label", Some(&attr_args))?,
+            "Log in with GitHub"
+        );
+
+        Ok(())
+    }
+
+    /// Ensures that a missing key and a present-but-broken message produce distinct
+    /// error variants, which is the whole point of [`lookup_checked`] over
+    /// [`t!`](crate::t)'s sentinel-string lookup.
+    #[test]
+    fn test_lookup_checked_distinguishes_missing_key_from_format_error() -> testresult::TestResult<()> {
+        let bundle = test_bundle("greeting = Hello, { $name }!\n");
+
+        let err = lookup_checked(&bundle, "nonexistent", None).unwrap_err();
+        assert!(matches!(err, Error::MessageNotFound { key, .. } if key == "nonexistent"));
+
+        // `name` is required by the pattern but no args are supplied.
+        let err = lookup_checked(&bundle, "greeting", None).unwrap_err();
+        assert!(matches!(err, Error::FormatErrors { key, .. } if key == "greeting"));
+
+        Ok(())
+    }
+}
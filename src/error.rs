@@ -1,5 +1,8 @@
 //! Error handling.
 
+use std::path::PathBuf;
+
+use fluent_templates::LanguageIdentifier;
 use unic_langid::LanguageIdentifierError;
 
 use crate::t;
@@ -15,6 +18,128 @@ pub enum Error {
         /// The source error.
         source: LanguageIdentifierError,
     },
+
+    /// An error occurred while (re)loading a runtime [`Localizer`](crate::Localizer)'s
+    /// locales directory.
+    #[error("{msg}\n{source}", msg = t!("error-runtime-loader-load", { "path" => path }))]
+    RuntimeLoaderLoad {
+        /// The locales directory that failed to load.
+        path: PathBuf,
+        /// The source error.
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    /// Returned by [`try_t!`](crate::try_t) when `key` could not be resolved against any
+    /// locale in the fallback chain.
+    #[error("{msg}", msg = t!("error-message-not-found", { "key" => key, "tried" => tried.join(", ") }))]
+    MessageNotFound {
+        /// The message key that could not be resolved.
+        key: String,
+        /// The locales (in fallback order) that were tried before giving up.
+        tried: Vec<String>,
+    },
+
+    /// Returned by [`bundle::lookup_checked`](crate::bundle::lookup_checked) when `key`
+    /// resolved to a message, but formatting its pattern produced one or more Fluent
+    /// errors (e.g. a placeable referencing a missing argument).
+    ///
+    /// Unlike [`MessageNotFound`](Error::MessageNotFound), this tells apart a key that
+    /// exists but is broken from one that's simply absent — something [`t!`](crate::t)/
+    /// [`try_t!`](crate::try_t) can't do, since they go through
+    /// [`fluent_templates::Loader`], which swallows format errors internally rather than
+    /// surfacing them. [`bundle::lookup_checked`](crate::bundle::lookup_checked) formats
+    /// the pattern directly against a [`FluentBundle`](fluent_templates::fluent_bundle::FluentBundle)
+    /// to make this distinction possible.
+    #[error("{msg}\n{errors}", msg = t!("error-format-errors", { "key" => key }), errors = errors.join("\n"))]
+    FormatErrors {
+        /// The message key that failed to format cleanly.
+        key: String,
+        /// The formatting errors reported by Fluent.
+        errors: Vec<String>,
+    },
+
+    /// Returned by [`bundle::load_bundle`](crate::bundle::load_bundle) when `locale`'s
+    /// directory is missing under the default asset root.
+    ///
+    /// A missing locale directory under a caller-supplied *override* root is not an error
+    /// (see [`load_bundle`](crate::bundle::load_bundle)'s docs); this variant is only for
+    /// the default root, where the directory is expected to always exist.
+    #[error("{msg}", msg = t!("error-missing-locale-dir", { "locale" => locale.to_string() }))]
+    MissingLocaleDir {
+        /// The locale whose directory could not be found.
+        locale: LanguageIdentifier,
+    },
+
+    /// An error occurred while reading a locales directory or one of the `.ftl` files in it.
+    #[error("{msg}\n{source}", msg = t!("error-read-locales-dir", { "path" => path }))]
+    ReadLocalesDir {
+        /// The directory or `.ftl` file that could not be read.
+        path: PathBuf,
+        /// The source error.
+        source: std::io::Error,
+    },
+
+    /// An error occurred while parsing a `.ftl` resource file.
+    #[error("{msg}\n{source}", msg = t!("error-parse-ftl", { "path" => path }))]
+    ParseFtl {
+        /// The `.ftl` file that failed to parse.
+        path: PathBuf,
+        /// The parser errors, formatted for display.
+        source: String,
+    },
+
+    /// An error occurred while adding a parsed `.ftl` resource to a [`FluentBundle`].
+    ///
+    /// [`FluentBundle`]: fluent_templates::fluent_bundle::FluentBundle
+    #[error("{msg}", msg = t!("error-add-resource", { "path" => path }))]
+    AddResource {
+        /// The `.ftl` file whose resource could not be added (e.g. due to a duplicate
+        /// message identifier already present in the bundle).
+        path: PathBuf,
+    },
+
+    /// Returned by [`bundle::load_bundle_from_assets`](crate::bundle::load_bundle_from_assets)
+    /// when an asset reported by [`I18nAssets::files_for_locale`](crate::assets::I18nAssets::files_for_locale)
+    /// could not be fetched.
+    #[error("{msg}", msg = t!("error-asset-not-found", { "path" => path }))]
+    AssetNotFound {
+        /// The asset path that was expected to exist but didn't.
+        path: String,
+    },
+
+    /// An error occurred while requesting a machine translation.
+    ///
+    /// Requires the `machine-translation` feature.
+    #[cfg(feature = "machine-translation")]
+    #[error("{msg}\n{source}", msg = t!("error-translation-request-failed"))]
+    TranslationRequestFailed {
+        /// The source error.
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    /// Returned by [`translate::translate_missing`](crate::translate::translate_missing) when
+    /// no [`TranslationProvider`](crate::translate::TranslationProvider) has been registered.
+    ///
+    /// Requires the `machine-translation` feature.
+    #[cfg(feature = "machine-translation")]
+    #[error("{msg}", msg = t!("error-translation-provider-unavailable"))]
+    TranslationProviderUnavailable,
+
+    /// Returned by [`locale::detect_locale`](crate::locale::detect_locale) when no signal
+    /// (explicit override, environment variables, or sampled text) yields a usable locale.
+    #[error("{msg}", msg = t!("error-locale-detection-failed"))]
+    LocaleDetectionFailed,
+
+    /// Returned by [`validate::validate_locales`](crate::validate::validate_locales) when
+    /// the `reference` locale's own directory could not be loaded, making it impossible to
+    /// know which keys every other locale should have.
+    #[error("{msg}\n{source}", msg = t!("error-reference-locale-unavailable", { "locale" => locale.to_string() }))]
+    ReferenceLocaleUnavailable {
+        /// The reference locale that could not be loaded.
+        locale: LanguageIdentifier,
+        /// The underlying error encountered while loading it.
+        source: Box<Error>,
+    },
 }
 
 #[cfg(test)]
@@ -1,15 +1,29 @@
 #![doc = include_str!("../README.md")]
 
 pub mod locale;
-pub use locale::{get_locale, set_locale};
+pub use locale::{get_locale, set_locale, with_locale};
+#[cfg(feature = "tokio")]
+pub use locale::with_locale_async;
 
 mod error;
 pub use error::Error;
 
+pub mod assets;
+pub mod bundle;
+
+#[cfg(feature = "machine-translation")]
+pub mod translate;
+
 mod value;
-pub use value::{FluentValue, ToFluentValue};
+pub use value::{date, money, FluentType, FluentValue, LocalizedDateTime, LocalizedNumber, ToFluentValue};
+
+#[doc(hidden)]
+pub mod macros;
+
+pub mod runtime;
+pub use runtime::Localizer;
 
-mod macros;
+pub mod validate;
 
 /// Re-export the [`fluent_templates`] crate.
 pub use fluent_templates;
@@ -25,6 +25,10 @@ thread_local! {
     /// This is thread-local storage, meaning each thread has its own instance of this variable.
     static CURRENT_LOCALE: RefCell<Option<LanguageIdentifier>> = const { RefCell::new(None) };
 
+    /// The [`fallback_chain`] computed for [`CURRENT_LOCALE`], cached by [`set_locale`] so
+    /// that [`t!`](crate::t)/[`try_t!`](crate::try_t) don't recompute it on every call.
+    static CURRENT_CHAIN: RefCell<Option<Vec<LanguageIdentifier>>> = const { RefCell::new(None) };
+
     /// Indicates whether the raw mode is enabled.
     ///
     /// When raw mode is enabled, translations will return the key itself instead of
@@ -35,6 +39,84 @@ thread_local! {
     ///
     /// This is thread-local storage, meaning each thread has its own instance of this variable.
     pub static RAW_MODE_ENABLED: RefCell<bool> = const { RefCell::new(false) };
+
+    /// A scoped locale override set by [`with_locale`], taking precedence over
+    /// [`CURRENT_LOCALE`] for the duration of the closure it was set for.
+    ///
+    /// Unlike [`CURRENT_LOCALE`], this is restored to its previous value once the
+    /// closure returns (or unwinds), so it cannot leak onto unrelated work running
+    /// later on the same thread-pool worker.
+    static LOCALE_OVERRIDE: RefCell<Option<LanguageIdentifier>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "tokio")]
+tokio::task_local! {
+    /// The task-scoped locale override set by [`with_locale_async`].
+    ///
+    /// Because this is a [`tokio::task_local!`] rather than a thread-local, it follows
+    /// the future across `.await` points even if the future resumes on a different
+    /// worker thread.
+    static LOCALE_TASK_OVERRIDE: LanguageIdentifier;
+}
+
+/// An RAII guard that restores the previous [`LOCALE_OVERRIDE`] when dropped.
+struct LocaleOverrideGuard {
+    previous: Option<LanguageIdentifier>,
+}
+
+impl Drop for LocaleOverrideGuard {
+    fn drop(&mut self) {
+        LOCALE_OVERRIDE.with(|cell| {
+            *cell.borrow_mut() = self.previous.take();
+        });
+    }
+}
+
+/// Runs `f` with the current locale scoped to `locale`, restoring the previous locale
+/// (if any) once `f` returns, even if `f` unwinds.
+///
+/// This does not touch [`CURRENT_LOCALE`], so it composes safely with [`set_locale`]: once
+/// the scope ends, [`get_locale`] goes back to whatever [`set_locale`] last configured.
+///
+/// # Errors
+///
+/// Returns an error if `locale` cannot be parsed into a [`LanguageIdentifier`].
+pub fn with_locale<R>(locale: &str, f: impl FnOnce() -> R) -> Result<R, Error> {
+    let langid = locale
+        .parse::<LanguageIdentifier>()
+        .map_err(|source| Error::LocaleParseError {
+            locale: locale.to_string(),
+            source,
+        })?;
+
+    let previous = LOCALE_OVERRIDE.with(|cell| cell.borrow_mut().replace(langid));
+    let _guard = LocaleOverrideGuard { previous };
+
+    Ok(f())
+}
+
+/// The async equivalent of [`with_locale`], built on [`tokio::task_local!`] so the
+/// override follows `fut` across `.await` points regardless of which worker thread it
+/// resumes on.
+///
+/// Requires the `tokio` feature.
+///
+/// # Errors
+///
+/// Returns an error if `locale` cannot be parsed into a [`LanguageIdentifier`].
+#[cfg(feature = "tokio")]
+pub async fn with_locale_async<F: std::future::Future>(
+    locale: &str,
+    fut: F,
+) -> Result<F::Output, Error> {
+    let langid = locale
+        .parse::<LanguageIdentifier>()
+        .map_err(|source| Error::LocaleParseError {
+            locale: locale.to_string(),
+            source,
+        })?;
+
+    Ok(LOCALE_TASK_OVERRIDE.scope(langid, fut).await)
 }
 
 /// The fallback locale used when no other locale is set or detected.
@@ -80,12 +162,17 @@ pub fn set_locale(locale: Option<&str>) -> Result<(), Error> {
                 locale: sys_loc.to_string(),
                 source,
             })?
+    } else if let Ok((detected, _)) = detect_locale(None, None) {
+        detected
     } else if let Some(fallback) = FALLBACK_LOCALE.get() {
         fallback.clone()
     } else {
         DEFAULT_LOCALE.clone()
     };
 
+    CURRENT_CHAIN.with(|cell| {
+        *cell.borrow_mut() = Some(fallback_chain(&langid));
+    });
     CURRENT_LOCALE.with(|cell| {
         *cell.borrow_mut() = Some(langid);
     });
@@ -97,15 +184,28 @@ pub fn set_locale(locale: Option<&str>) -> Result<(), Error> {
 ///
 /// This function retrieves the current locale by:
 ///
-/// 1. Checking if a current locale is set using [`set_locale`].
-/// 2. If current locale is not set, the fallback locale is returned instead.
-/// 3. If neither current nor fallback locale are set, the default locale (`"en-US"`) is returned.
+/// 1. Checking for a task-scoped override set by [`with_locale_async`] (requires the `tokio`
+///    feature).
+/// 2. Checking for a scoped override set by [`with_locale`].
+/// 3. Checking if a current locale is set using [`set_locale`].
+/// 4. If none of the above are set, the fallback locale is returned instead.
+/// 5. If neither current nor fallback locale are set, the default locale (`"en-US"`) is returned.
 ///
 /// # Thread Safety
 ///
-/// This function is thread-safe and uses thread-local storage to manage the current locale.
-/// In other words, each thread has its own instance of the current locale.
+/// This function is thread-safe and uses thread-local (and, behind the `tokio` feature,
+/// task-local) storage to manage the current locale. In other words, each thread (or task)
+/// has its own instance of the current locale.
 pub fn get_locale() -> LanguageIdentifier {
+    #[cfg(feature = "tokio")]
+    if let Ok(locale) = LOCALE_TASK_OVERRIDE.try_with(LanguageIdentifier::clone) {
+        return locale;
+    }
+
+    if let Some(locale) = LOCALE_OVERRIDE.with(|cell| cell.borrow().clone()) {
+        return locale;
+    }
+
     CURRENT_LOCALE.with(|cell| {
         cell.borrow()
             .clone()
@@ -114,6 +214,136 @@ pub fn get_locale() -> LanguageIdentifier {
     })
 }
 
+/// Returns the [`fallback_chain`] for the currently active locale.
+///
+/// When a scoped override is active (via [`with_locale`] or [`with_locale_async`]), this
+/// computes the chain fresh for that override. Otherwise it reuses the chain cached by the
+/// most recent [`set_locale`] call, avoiding recomputing it on every [`t!`](crate::t) call.
+///
+/// This is used by the [`t!`]/[`try_t!`] macros and is not usually called directly.
+///
+/// [`t!`]: crate::t
+#[doc(hidden)]
+pub fn current_fallback_chain() -> Vec<LanguageIdentifier> {
+    #[cfg(feature = "tokio")]
+    if let Ok(locale) = LOCALE_TASK_OVERRIDE.try_with(LanguageIdentifier::clone) {
+        return fallback_chain(&locale);
+    }
+
+    if let Some(locale) = LOCALE_OVERRIDE.with(|cell| cell.borrow().clone()) {
+        return fallback_chain(&locale);
+    }
+
+    CURRENT_CHAIN.with(|cell| {
+        cell.borrow()
+            .clone()
+            .unwrap_or_else(|| fallback_chain(&get_locale()))
+    })
+}
+
+/// Builds an ordered list of fallback candidates for the given `locale`.
+///
+/// This follows the spirit of [ICU4X's locale fallback algorithm], progressively
+/// stripping subtags from the most specific form down to the base language:
+///
+/// 1. The full locale as given (`lang-Script-REGION-variants`).
+/// 2. The locale without its variants.
+/// 3. The locale without its region.
+/// 4. The locale without its script, *if and only if* that script is the likely/default
+///    script for the language (see [`is_default_script`]).
+/// 5. The bare language subtag, under the same script condition as step 4.
+/// 6. The configured [`FALLBACK_LOCALE`], if set; otherwise the [`DEFAULT_LOCALE`]
+///    (`"en-US"`).
+///
+/// Steps 4 and 5 are skipped when the locale carries a script that isn't the likely
+/// default for its language (e.g. `zh-Hant`, where `Hans` is the default script for
+/// `zh`). Stripping the script there would have the chain silently fall back to a bare
+/// `zh` entry that, in practice, means simplified Chinese — the wrong writing system for
+/// a `Hant` request. In that case the chain goes straight from the region-stripped form
+/// to [`FALLBACK_LOCALE`]/[`DEFAULT_LOCALE`].
+///
+/// Candidates that are identical to a previous one are skipped, and the chain always
+/// terminates with *the configured fallback locale* — [`DEFAULT_LOCALE`] is only used as
+/// the terminator when no [`FALLBACK_LOCALE`] has been set, so a deliberately narrower
+/// `FALLBACK_LOCALE` (e.g. `fr-FR`) isn't silently widened back out to English.
+///
+/// [ICU4X's locale fallback algorithm]: https://github.com/unicode-org/icu4x/blob/main/documents/design/locid_transform/locale_fallback.md
+pub fn fallback_chain(locale: &LanguageIdentifier) -> Vec<LanguageIdentifier> {
+    let mut candidates = Vec::new();
+    let mut push = |candidate: LanguageIdentifier| {
+        if !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+    };
+
+    push(locale.clone());
+
+    if locale.variants().next().is_some() {
+        let mut without_variants = locale.clone();
+        without_variants.clear_variants();
+        push(without_variants);
+    }
+
+    if locale.region.is_some() {
+        let mut without_region = locale.clone();
+        without_region.region = None;
+        without_region.clear_variants();
+        push(without_region);
+    }
+
+    // Dropping the script is only safe when it's the language's likely/default script;
+    // otherwise the resulting bare-language candidate would imply a different script.
+    let script_is_default = locale
+        .script
+        .map_or(true, |script| is_default_script(locale.language, script));
+
+    if locale.script.is_some() && script_is_default {
+        let mut without_script = locale.clone();
+        without_script.script = None;
+        without_script.region = None;
+        without_script.clear_variants();
+        push(without_script);
+    }
+
+    if script_is_default {
+        let mut language_only = locale.clone();
+        language_only.script = None;
+        language_only.region = None;
+        language_only.clear_variants();
+        push(language_only);
+    }
+
+    match FALLBACK_LOCALE.get() {
+        Some(fallback) => push(fallback.clone()),
+        None => push(DEFAULT_LOCALE.clone()),
+    }
+
+    candidates
+}
+
+/// Reports whether `script` is the likely/default script for `language`, per a small
+/// table of the common cases where a language has more than one script in active use.
+///
+/// This is not a full ICU4X likely-subtags table — just enough to stop
+/// [`fallback_chain`] from stripping a script when doing so would silently change the
+/// writing system (e.g. `zh-Hant` degrading to bare `zh`, which in practice means
+/// simplified Chinese). Languages not listed here are assumed to have a single script in
+/// practice, so any script they carry is treated as the default.
+fn is_default_script(language: unic_langid::subtags::Language, script: unic_langid::subtags::Script) -> bool {
+    let default_script = match language.as_str() {
+        "zh" => "Hans",
+        "sr" => "Cyrl",
+        "az" => "Latn",
+        "uz" => "Latn",
+        "pa" => "Guru",
+        "mn" => "Cyrl",
+        "yue" => "Hant",
+        _ => return true,
+    };
+
+    script.as_str() == default_script
+}
+
 /// Enables or disables the raw mode.
 ///
 /// When raw mode is enabled, translations will return the key itself instead of
@@ -125,6 +355,156 @@ pub fn set_raw_mode(enabled: bool) {
     });
 }
 
+/// How a locale returned by [`detect_locale`] was determined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectionConfidence {
+    /// Taken verbatim from an explicit override.
+    Explicit,
+    /// Parsed from the `LC_ALL`/`LANG` environment variables.
+    Environment,
+    /// Inferred by sampling a UI string's script/language.
+    ///
+    /// Requires the `whatlang` feature.
+    Sampled,
+}
+
+/// Picks an initial locale by inspecting, in order:
+///
+/// 1. `explicit_override`, if given.
+/// 2. The `LC_ALL`/`LANG` environment variables (`LC_ALL` takes precedence, per POSIX),
+///    stripping encoding suffixes like `.UTF-8` and normalizing `_` to `-` (e.g.
+///    `ja_JP.UTF-8` becomes `ja-JP`).
+/// 3. `sample_text`, passed through a script/language detector (requires the `whatlang`
+///    feature).
+///
+/// # Errors
+///
+/// Returns [`Error::LocaleParseError`] if `explicit_override` is given but isn't a valid
+/// [`LanguageIdentifier`], or [`Error::LocaleDetectionFailed`] if none of the above yields
+/// a usable identifier.
+pub fn detect_locale(
+    explicit_override: Option<&str>,
+    sample_text: Option<&str>,
+) -> Result<(LanguageIdentifier, DetectionConfidence), Error> {
+    if let Some(explicit) = explicit_override {
+        let langid = explicit
+            .parse::<LanguageIdentifier>()
+            .map_err(|source| Error::LocaleParseError {
+                locale: explicit.to_string(),
+                source,
+            })?;
+        return Ok((langid, DetectionConfidence::Explicit));
+    }
+
+    if let Ok(env_value) = std::env::var("LC_ALL").or_else(|_| std::env::var("LANG")) {
+        let stripped = env_value
+            .split('.')
+            .next()
+            .unwrap_or(&env_value)
+            .replace('_', "-");
+        if let Ok(langid) = stripped.parse::<LanguageIdentifier>() {
+            return Ok((langid, DetectionConfidence::Environment));
+        }
+    }
+
+    #[cfg(feature = "whatlang")]
+    if let Some(text) = sample_text {
+        if let Some(info) = whatlang::detect(text) {
+            if let Ok(langid) = info.lang().code().parse::<LanguageIdentifier>() {
+                return Ok((langid, DetectionConfidence::Sampled));
+            }
+        }
+    }
+    #[cfg(not(feature = "whatlang"))]
+    let _ = sample_text;
+
+    Err(Error::LocaleDetectionFailed)
+}
+
+/// Chooses the best locale for `requested` out of `available`, using standard
+/// language-range matching.
+///
+/// Preferences are tried in order; for each one, progressively looser matches against
+/// `available` are attempted before moving on to the next preference:
+///
+/// 1. An exact match (language, script, region and variants all equal).
+/// 2. A language + script match (ignoring region/variants).
+/// 3. A language-only match.
+///
+/// If no preference matches anything in `available`, falls back to [`FALLBACK_LOCALE`],
+/// then [`DEFAULT_LOCALE`].
+///
+/// Pass the loader's own locale set as `available`, e.g. `LOCALES.locales()`, so the
+/// result is guaranteed to be a locale the loader can actually serve.
+pub fn negotiate_locale<'a>(
+    available: impl Iterator<Item = &'a LanguageIdentifier>,
+    requested: &[LanguageIdentifier],
+) -> LanguageIdentifier {
+    let available: Vec<&LanguageIdentifier> = available.collect();
+
+    for pref in requested {
+        if let Some(found) = available.iter().find(|loc| **loc == pref) {
+            return (*found).clone();
+        }
+        if let Some(found) = available
+            .iter()
+            .find(|loc| loc.language == pref.language && loc.script == pref.script)
+        {
+            return (*found).clone();
+        }
+        if let Some(found) = available.iter().find(|loc| loc.language == pref.language) {
+            return (*found).clone();
+        }
+    }
+
+    FALLBACK_LOCALE
+        .get()
+        .cloned()
+        .unwrap_or_else(|| DEFAULT_LOCALE.clone())
+}
+
+/// Negotiates the best locale for `requested` against `available` (see
+/// [`negotiate_locale`]) and commits it as the current locale, the same way
+/// `set_locale(Some(...))` would.
+///
+/// Unlike [`set_locale`], which accepts any syntactically valid locale string even if the
+/// loader has no translations for it, this guarantees the committed locale is one the
+/// loader can actually serve.
+pub fn set_locale_negotiated<'a>(
+    available: impl Iterator<Item = &'a LanguageIdentifier>,
+    requested: &[LanguageIdentifier],
+) {
+    let negotiated = negotiate_locale(available, requested);
+    CURRENT_CHAIN.with(|cell| {
+        *cell.borrow_mut() = Some(fallback_chain(&negotiated));
+    });
+    CURRENT_LOCALE.with(|cell| {
+        *cell.borrow_mut() = Some(negotiated);
+    });
+}
+
+/// Parses an HTTP `Accept-Language` header value into an ordered list of preferences,
+/// suitable for passing to [`negotiate_locale`]/[`set_locale_negotiated`].
+///
+/// Entries are sorted by descending `q` weight (default `1.0` when omitted); entries that
+/// fail to parse as a [`LanguageIdentifier`] are skipped.
+pub fn parse_accept_language(header: &str) -> Vec<LanguageIdentifier> {
+    let mut weighted: Vec<(f32, LanguageIdentifier)> = header
+        .split(',')
+        .filter_map(|part| {
+            let part = part.trim();
+            let (tag, q) = match part.split_once(";q=") {
+                Some((tag, q)) => (tag.trim(), q.trim().parse::<f32>().unwrap_or(1.0)),
+                None => (part, 1.0),
+            };
+            tag.parse::<LanguageIdentifier>().ok().map(|id| (q, id))
+        })
+        .collect();
+
+    weighted.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    weighted.into_iter().map(|(_, id)| id).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::{env, str::FromStr};
@@ -135,6 +515,163 @@ mod tests {
     use super::*;
     use crate::t;
 
+    /// Ensures that [`detect_locale`] prefers an explicit override, then the `LC_ALL`/`LANG`
+    /// environment variables (stripping encoding suffixes), and fails cleanly otherwise.
+    #[test]
+    fn test_detect_locale_precedence() -> TestResult<()> {
+        unsafe {
+            env::remove_var("LANG");
+            env::remove_var("LC_ALL");
+        }
+
+        let (locale, confidence) = detect_locale(Some("fr-FR"), None)?;
+        assert_eq!(locale, langid!("fr-FR"));
+        assert_eq!(confidence, DetectionConfidence::Explicit);
+
+        unsafe {
+            env::set_var("LANG", "ja_JP.UTF-8");
+        }
+        let (locale, confidence) = detect_locale(None, None)?;
+        assert_eq!(locale, langid!("ja-JP"));
+        assert_eq!(confidence, DetectionConfidence::Environment);
+
+        unsafe {
+            env::set_var("LC_ALL", "de_DE.UTF-8");
+        }
+        let (locale, confidence) = detect_locale(None, None)?;
+        assert_eq!(locale, langid!("de-DE"));
+        assert_eq!(confidence, DetectionConfidence::Environment);
+
+        unsafe {
+            env::remove_var("LANG");
+            env::remove_var("LC_ALL");
+        }
+        assert!(matches!(
+            detect_locale(None, None),
+            Err(Error::LocaleDetectionFailed)
+        ));
+
+        Ok(())
+    }
+
+    /// Ensures that [`current_fallback_chain`] reuses the chain cached by [`set_locale`].
+    #[test]
+    fn test_current_fallback_chain_matches_set_locale() -> TestResult<()> {
+        set_locale(Some("zh-Hant-HK"))?;
+        assert_eq!(
+            current_fallback_chain(),
+            fallback_chain(&langid!("zh-Hant-HK"))
+        );
+
+        Ok(())
+    }
+
+    /// Ensures that [`negotiate_locale`] prefers an exact match, then falls back to
+    /// looser language-only matches against the available locale set.
+    #[test]
+    fn test_negotiate_locale_prefers_closest_match() {
+        let available = [langid!("en-US"), langid!("fr-FR"), langid!("ja-JP")];
+
+        // Exact match.
+        let negotiated = negotiate_locale(available.iter(), &[langid!("fr-FR")]);
+        assert_eq!(negotiated, langid!("fr-FR"));
+
+        // No exact match for `fr-CA`, but `fr-FR` matches in the language + script pass
+        // (neither carries a script).
+        let negotiated = negotiate_locale(available.iter(), &[langid!("fr-CA")]);
+        assert_eq!(negotiated, langid!("fr-FR"));
+
+        // Nothing matches; falls back to the default locale.
+        let negotiated = negotiate_locale(available.iter(), &[langid!("de-DE")]);
+        assert_eq!(negotiated, DEFAULT_LOCALE.clone());
+    }
+
+    /// Ensures that `Accept-Language` headers are parsed and ordered by `q` weight.
+    #[test]
+    fn test_parse_accept_language_orders_by_weight() {
+        let preferences = parse_accept_language("fr-FR;q=0.5, en-US;q=0.9, ja-JP");
+        assert_eq!(
+            preferences,
+            vec![langid!("ja-JP"), langid!("en-US"), langid!("fr-FR")]
+        );
+    }
+
+    /// Ensures that [`with_locale`] overrides [`get_locale`] only for the duration of
+    /// the closure, restoring the previous locale afterwards.
+    #[test]
+    fn test_with_locale_is_scoped() -> TestResult<()> {
+        set_locale(Some("en-US"))?;
+
+        with_locale("fr-FR", || {
+            assert_eq!(t!("greeting"), "Bonjour, le monde!");
+        })?;
+
+        assert_eq!(t!("greeting"), "Hello, world!");
+        Ok(())
+    }
+
+    /// Ensures that nested [`with_locale`] scopes restore the enclosing override,
+    /// not the ambient locale, once they end.
+    #[test]
+    fn test_with_locale_nesting_restores_enclosing_scope() -> TestResult<()> {
+        set_locale(Some("en-US"))?;
+
+        with_locale("fr-FR", || -> TestResult<()> {
+            with_locale("ja-JP", || {
+                assert_eq!(get_locale(), langid!("ja-JP"));
+            })?;
+
+            assert_eq!(get_locale(), langid!("fr-FR"));
+            Ok(())
+        })??;
+
+        assert_eq!(get_locale(), langid!("en-US"));
+        Ok(())
+    }
+
+    /// Ensures that the fallback chain strips subtags in the expected order
+    /// and always terminates with the default locale.
+    #[test]
+    fn test_fallback_chain_strips_subtags() -> TestResult<()> {
+        // `ja` has a single script in practice, so `Jpan` is its likely/default script
+        // and the chain strips all the way down to the bare language subtag.
+        let locale = langid!("ja-Jpan-JP");
+        let chain = fallback_chain(&locale);
+
+        assert_eq!(
+            chain,
+            vec![langid!("ja-Jpan-JP"), langid!("ja-Jpan"), langid!("ja"), DEFAULT_LOCALE.clone()]
+        );
+
+        Ok(())
+    }
+
+    /// Ensures that a non-default script (e.g. `Hant` for `zh`, whose likely/default
+    /// script is `Hans`) stops the chain above the bare language subtag, instead of
+    /// silently falling back to a candidate that implies the wrong writing system.
+    #[test]
+    fn test_fallback_chain_keeps_non_default_script() -> TestResult<()> {
+        let locale = langid!("zh-Hant-HK");
+        let chain = fallback_chain(&locale);
+
+        assert_eq!(
+            chain,
+            vec![langid!("zh-Hant-HK"), langid!("zh-Hant"), DEFAULT_LOCALE.clone()]
+        );
+
+        Ok(())
+    }
+
+    /// Ensures that duplicate candidates produced by stripping subtags are
+    /// not repeated in the chain.
+    #[test]
+    fn test_fallback_chain_deduplicates() -> TestResult<()> {
+        let chain = fallback_chain(&langid!("en"));
+        assert_eq!(chain, vec![langid!("en"), DEFAULT_LOCALE.clone()]);
+
+        Ok(())
+    }
+
     /// Ensures that the missing keys fallback to the English locale.
     #[test]
     fn test_localization_fallback_to_english() -> TestResult<()> {
@@ -80,6 +80,42 @@ macro_rules! i18n {
 ///   The argument values must implement the [`ToFluentValue`] trait, which allows
 ///   converting various types to a [`FluentValue`].
 ///
+/// # Message attributes
+///
+/// Fluent messages can carry attributes (e.g. `login-button.aria-label = ...`). Request
+/// one by passing the dotted `key.attribute` form as the key, with or without arguments:
+///
+/// ```rust,ignore
+/// let label = t!("login-button.aria-label");
+/// let label = t!("login-button.aria-label", { "service" => name });
+/// ```
+///
+/// This resolves the same way as a top-level message: the `key` part is looked up, then
+/// the `attribute` pattern is formatted instead of the message's value. If the message or
+/// the attribute is missing, the same "Unknown localization" behavior as a missing
+/// top-level key applies.
+///
+/// # Known limitation: attribute formatting goes through `Loader`, not a bundle directly
+///
+/// `t!`/`try_t!` always resolve through [`fluent_templates::Loader`] (whichever backs the
+/// loader in scope — a [`static_loader!`](fluent_templates::static_loader)-generated type,
+/// [`ArcLoader`](fluent_templates::ArcLoader), or [`Localizer`](crate::runtime::Localizer)),
+/// none of which expose their underlying `FluentBundle`s publicly. So the dotted
+/// `key.attribute` form here relies on [`Loader::lookup`](fluent_templates::Loader::lookup)/
+/// [`lookup_with_args`](fluent_templates::Loader::lookup_with_args) splitting it and
+/// formatting the attribute pattern themselves, rather than this crate walking below
+/// `Loader` to do so itself — that would require forking the dependency.
+///
+/// This is an intentional, accepted scope decision, not an oversight: callers who need
+/// attribute (or generally format-error-aware) lookups that don't depend on this upstream
+/// behavior can use [`bundle::lookup_checked`](crate::bundle::lookup_checked) instead,
+/// which formats directly against a [`FluentBundle`](fluent_templates::fluent_bundle::FluentBundle)
+/// loaded via [`bundle::load_bundle`](crate::bundle::load_bundle)/
+/// [`load_bundle_from_assets`](crate::bundle::load_bundle_from_assets) and supports the
+/// same dotted form. `test_message_attribute_lookup` in this module's tests pins the
+/// `Loader` behavior `t!` relies on, so a future `fluent-templates` release that drops it
+/// is caught here rather than silently changing `t!`'s behavior.
+///
 /// # Note
 ///
 /// Call [`i18n!`] macro to initialize the i18n system with default static loader
@@ -127,7 +163,7 @@ macro_rules! t {
     // t!("key")
     ($key:expr) => {{
         use $crate::fluent_templates::Loader;
-        crate::LOCALES.lookup(&$crate::get_locale(), $key)
+        $crate::macros::walk_fallback_chain($key, |locale| crate::LOCALES.lookup(locale, $key))
     }};
 
     // t!("key", { arg => val, ... })
@@ -139,13 +175,13 @@ macro_rules! t {
         $(
             args.insert(Cow::Borrowed($arg), $val.to_fluent_value());
         )+
-        crate::LOCALES.lookup_with_args(&$crate::get_locale(), $key, &args)
+        $crate::macros::walk_fallback_chain($key, |locale| crate::LOCALES.lookup_with_args(locale, $key, &args))
     }};
 
     // t!(LOCALES, "key")
     ($locales:expr, $key:expr) => {{
         use $crate::fluent_templates::Loader;
-        $locales.lookup(&$crate::get_locale(), $key)
+        $crate::macros::walk_fallback_chain($key, |locale| $locales.lookup(locale, $key))
     }};
 
     // t!(LOCALES, "key", { arg => val, ... })
@@ -157,10 +193,147 @@ macro_rules! t {
         $(
             args.insert(Cow::Borrowed($arg), $val.to_fluent_value());
         )+
-        $locales.lookup_with_args(&$crate::get_locale(), $key, &args)
+        $crate::macros::walk_fallback_chain($key, |locale| $locales.lookup_with_args(locale, $key, &args))
     }};
 }
 
+/// The fallible counterpart of [`t!`].
+///
+/// Instead of returning the "Unknown localization" sentinel string when a key cannot be
+/// resolved, this returns a structured [`Error`](crate::Error), so callers can decide
+/// whether to fall back, log, or hard-fail (e.g. in a CI lint that rejects untranslated
+/// keys). Accepts the same forms as [`t!`]:
+///
+/// 1. `try_t!("key")`
+/// 2. `try_t!("key", { arg1 => value1, ... })`
+/// 3. `try_t!(LOCALES, "key")`
+/// 4. `try_t!(LOCALES, "key", { arg1 => value1, ... })`
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! try_t {
+    // try_t!("key")
+    ($key:expr) => {{
+        use $crate::fluent_templates::Loader;
+        $crate::macros::walk_fallback_chain_checked($key, |locale| crate::LOCALES.lookup(locale, $key))
+    }};
+
+    // try_t!("key", { arg => val, ... })
+    ($key:expr, { $($arg:expr => $val:expr),+ $(,)? }) => {{
+        use $crate::fluent_templates::Loader;
+        use $crate::ToFluentValue;
+        use std::borrow::Cow;
+        let mut args = ::std::collections::HashMap::new();
+        $(
+            args.insert(Cow::Borrowed($arg), $val.to_fluent_value());
+        )+
+        $crate::macros::walk_fallback_chain_checked($key, |locale| crate::LOCALES.lookup_with_args(locale, $key, &args))
+    }};
+
+    // try_t!(LOCALES, "key")
+    ($locales:expr, $key:expr) => {{
+        use $crate::fluent_templates::Loader;
+        $crate::macros::walk_fallback_chain_checked($key, |locale| $locales.lookup(locale, $key))
+    }};
+
+    // try_t!(LOCALES, "key", { arg => val, ... })
+    ($locales:expr, $key:expr, { $($arg:expr => $val:expr),+ $(,)? }) => {{
+        use $crate::fluent_templates::Loader;
+        use $crate::ToFluentValue;
+        use std::borrow::Cow;
+        let mut args = ::std::collections::HashMap::new();
+        $(
+            args.insert(Cow::Borrowed($arg), $val.to_fluent_value());
+        )+
+        $crate::macros::walk_fallback_chain_checked($key, |locale| $locales.lookup_with_args(locale, $key, &args))
+    }};
+}
+
+/// Walks the [`fallback_chain`](crate::locale::fallback_chain) of the current locale,
+/// calling `lookup` against each candidate until one returns something other than the
+/// "Unknown localization" sentinel, or the chain is exhausted.
+///
+/// With the `machine-translation` feature enabled, a hit on a candidate *other than* the
+/// originally-requested locale is machine-translated back into the requested locale via
+/// [`translate_missing`](crate::translate::translate_missing) before being returned,
+/// rather than being handed back verbatim in the wrong language. If no provider is
+/// registered, or the request fails, this falls back to returning the untranslated text
+/// as before.
+///
+/// With the `log-miss-tr` feature enabled, exhausting the chain without a hit emits a
+/// [`log::warn!`] naming `key` and every locale that was tried, so missing translations
+/// show up in application logs instead of silently rendering the sentinel string.
+///
+/// This is an implementation detail of the [`t!`] macro and is not meant to be called
+/// directly; it is exposed from this module purely so the macro expansion can reach it.
+#[doc(hidden)]
+pub fn walk_fallback_chain(
+    #[cfg_attr(
+        not(any(feature = "log-miss-tr", feature = "machine-translation")),
+        allow(unused_variables)
+    )]
+    key: &str,
+    mut lookup: impl FnMut(&fluent_templates::LanguageIdentifier) -> String,
+) -> String {
+    let chain = crate::locale::current_fallback_chain();
+    let requested = chain.first();
+    let mut last = String::new();
+    for candidate in &chain {
+        last = lookup(candidate);
+        if !last.starts_with("Unknown localization") {
+            #[cfg(feature = "machine-translation")]
+            if let Some(requested) = requested {
+                if candidate != requested {
+                    if let Ok(translated) =
+                        crate::translate::translate_missing(key, &last, candidate, requested)
+                    {
+                        return translated;
+                    }
+                }
+            }
+            return last;
+        }
+    }
+    #[cfg(feature = "log-miss-tr")]
+    log::warn!(
+        "missing translation for key {key:?}; tried locales: [{}]",
+        chain.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    );
+    last
+}
+
+/// The fallible counterpart of [`walk_fallback_chain`], used by [`try_t!`].
+///
+/// Returns [`Error::MessageNotFound`] instead of the sentinel string once the chain is
+/// exhausted without a hit. As with [`walk_fallback_chain`], exhausting the chain emits a
+/// [`log::warn!`] behind the `log-miss-tr` feature.
+///
+/// This is an implementation detail of the [`try_t!`] macro and is not meant to be called
+/// directly; it is exposed from this module purely so the macro expansion can reach it.
+#[doc(hidden)]
+pub fn walk_fallback_chain_checked(
+    key: &str,
+    mut lookup: impl FnMut(&fluent_templates::LanguageIdentifier) -> String,
+) -> Result<String, crate::Error> {
+    let chain = crate::locale::current_fallback_chain();
+    let mut tried = Vec::with_capacity(chain.len());
+    for candidate in &chain {
+        let result = lookup(candidate);
+        if !result.starts_with("Unknown localization") {
+            return Ok(result);
+        }
+        tried.push(candidate.to_string());
+    }
+    #[cfg(feature = "log-miss-tr")]
+    log::warn!(
+        "missing translation for key {key:?}; tried locales: [{}]",
+        tried.join(", ")
+    );
+    Err(crate::Error::MessageNotFound {
+        key: key.to_string(),
+        tried,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::set_locale;
@@ -180,6 +353,41 @@ mod tests {
         Ok(())
     }
 
+    // Ensures that the dotted `key.attribute` form resolves a message attribute
+    // rather than the message's own value. This pins `fluent-templates`'s `Loader::lookup`
+    // behavior of splitting `key.attribute` itself, which `t!`'s attribute support depends
+    // on (see the "Message attributes" section on `t!`'s doc comment); if a future
+    // `fluent-templates` release drops that support, this test is what catches it.
+    #[test]
+    fn test_message_attribute_lookup() -> testresult::TestResult<()> {
+        set_locale(Some("en-US"))?;
+
+        assert_eq!(
+            t!("login-button.aria-label", { "service" => "GitHub" }),
+            "Log in with GitHub"
+        );
+        assert_eq!(
+            t!("login-button.nonexistent-attribute"),
+            "Unknown localization key: \"login-button.nonexistent-attribute\""
+        );
+
+        Ok(())
+    }
+
+    // Ensures that `try_t!` returns `Ok` for resolvable keys and a structured
+    // `MessageNotFound` error once the fallback chain is exhausted.
+    #[test]
+    fn test_try_t_returns_structured_error() -> testresult::TestResult<()> {
+        set_locale(Some("en-US"))?;
+
+        assert_eq!(try_t!("greeting")?, "Hello, world!");
+
+        let err = try_t!("nonexistent-key").unwrap_err();
+        assert!(matches!(err, crate::Error::MessageNotFound { key, .. } if key == "nonexistent-key"));
+
+        Ok(())
+    }
+
     // Ensures that Latin script names are NOT isolated in RTL context
     // since the Unicode directional isolation is disabled.
     #[test]
@@ -0,0 +1,131 @@
+//! Runtime, hot-reloadable locale loading.
+//!
+//! Unlike the [`i18n!`] macro, which bakes every `.ftl` file into the binary at compile
+//! time via [`fluent_templates::static_loader!`], [`Localizer`] re-reads its locales
+//! directory from disk on every [`Localizer::reload`] call. This is useful for
+//! developer tools and long-running servers where translators need to edit translations
+//! and see the result without a recompile.
+//!
+//! [`i18n!`]: crate::i18n
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use fluent_templates::{ArcLoader, FluentValue, LanguageIdentifier, Loader};
+
+use crate::Error;
+
+/// A runtime locale loader that can be [reloaded](Localizer::reload) without recompiling.
+///
+/// Internally this wraps an [`ArcLoader`] behind an [`RwLock`]. A [`reload`](Localizer::reload)
+/// rebuilds a fresh [`ArcLoader`] from disk and atomically swaps it in, so concurrent
+/// [`t!`](crate::t) calls either see the loader from before or after the reload, but
+/// never a torn mix of old and new bundles.
+pub struct Localizer {
+    loader: RwLock<Arc<ArcLoader>>,
+    locales_dir: PathBuf,
+    fallback: LanguageIdentifier,
+}
+
+impl Localizer {
+    /// Builds a new [`Localizer`] by loading the `.ftl` resources under `locales_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the locales directory cannot be read or its resources fail to parse.
+    pub fn new(locales_dir: impl Into<PathBuf>, fallback: LanguageIdentifier) -> Result<Self, Error> {
+        let locales_dir = locales_dir.into();
+        let loader = Self::build_loader(&locales_dir, &fallback)?;
+
+        Ok(Self {
+            loader: RwLock::new(Arc::new(loader)),
+            locales_dir,
+            fallback,
+        })
+    }
+
+    /// Re-reads the locales directory from disk and atomically swaps in the new bundle.
+    ///
+    /// In-flight [`lookup`](Localizer::lookup)/[`lookup_with_args`](Localizer::lookup_with_args)
+    /// calls that already cloned the [`Arc`] keep using the loader they observed; new calls see
+    /// the freshly reloaded one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the locales directory cannot be read or its resources fail to parse.
+    /// On error, the previously loaded bundle is left in place.
+    pub fn reload(&self) -> Result<(), Error> {
+        let loader = Self::build_loader(&self.locales_dir, &self.fallback)?;
+        *self.loader.write().expect("runtime loader lock poisoned") = Arc::new(loader);
+        Ok(())
+    }
+
+    /// Returns the list of locales currently loaded, so callers can watch the locales
+    /// directory (e.g. via `inotify`) and know what to compare against after a reload.
+    pub fn locales(&self) -> Vec<LanguageIdentifier> {
+        self.current().locales().cloned().collect()
+    }
+
+    /// Looks up `text_id` in `lang`, delegating to the currently loaded bundle.
+    ///
+    /// This has the same name and signature as [`Loader::lookup`] so that [`t!`](crate::t)
+    /// can call it without needing [`Localizer`] to implement the full [`Loader`] trait.
+    pub fn lookup(&self, lang: &LanguageIdentifier, text_id: &str) -> String {
+        self.current().lookup(lang, text_id)
+    }
+
+    /// Looks up `text_id` in `lang` with arguments, delegating to the currently loaded bundle.
+    pub fn lookup_with_args(
+        &self,
+        lang: &LanguageIdentifier,
+        text_id: &str,
+        args: &HashMap<Cow<str>, FluentValue>,
+    ) -> String {
+        self.current().lookup_with_args(lang, text_id, args)
+    }
+
+    /// Clones the currently active loader [`Arc`].
+    fn current(&self) -> Arc<ArcLoader> {
+        Arc::clone(&self.loader.read().expect("runtime loader lock poisoned"))
+    }
+
+    fn build_loader(locales_dir: &Path, fallback: &LanguageIdentifier) -> Result<ArcLoader, Error> {
+        ArcLoader::builder(locales_dir, fallback.clone())
+            .customise(|bundle| bundle.set_use_isolating(false))
+            .build()
+            .map_err(|source| Error::RuntimeLoaderLoad {
+                path: locales_dir.to_path_buf(),
+                source,
+            })
+    }
+}
+
+/// Macro to initialize a hot-reloadable [`Localizer`] alongside the static [`i18n!`] loader.
+///
+/// It should be called at the start of the application, the same way as [`i18n!`], and
+/// supports the same two forms of usage:
+///
+/// 1. `i18n_runtime!("locales", fallback = "en-US")`
+/// 2. `i18n_runtime!("locales")` (uses the default fallback locale, `"en-US"`)
+///
+/// Unlike [`i18n!`], this defines a `LOCALES` static holding a lazily initialized
+/// [`Localizer`] (a [`LazyLock<Localizer>`](std::sync::LazyLock)), which can be
+/// [reloaded](Localizer::reload) at runtime and passed to [`t!`](crate::t) the same way a
+/// custom static loader is: `t!(LOCALES, "key", { ... })`.
+#[macro_export]
+macro_rules! i18n_runtime {
+    ($dir:expr, fallback = $fallback:literal) => {
+        static LOCALES: ::std::sync::LazyLock<$crate::runtime::Localizer> =
+            ::std::sync::LazyLock::new(|| {
+                $crate::runtime::Localizer::new($dir, $fallback.parse().expect("invalid fallback locale"))
+                    .expect("failed to initialize runtime i18n loader")
+            });
+    };
+    ($dir:expr) => {
+        $crate::i18n_runtime!($dir, fallback = "en-US");
+    };
+}
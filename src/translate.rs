@@ -0,0 +1,123 @@
+//! Optional machine-translation fallback for keys missing from the active locale.
+//!
+//! This is entirely opt-in (behind the `machine-translation` feature) and is meant for
+//! apps shipping incomplete locales: when a key is absent, the base-locale string can be
+//! sent to an external translation endpoint and the result cached, rather than falling
+//! back to a raw "Unknown localization" string or the base locale's text verbatim.
+
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex, OnceLock},
+};
+
+use fluent_templates::LanguageIdentifier;
+
+use crate::Error;
+
+/// A source of machine translations for a single string at a time.
+pub trait TranslationProvider {
+    /// Translates `text` from `from` to `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying translation request fails.
+    fn translate(
+        &self,
+        text: &str,
+        from: &LanguageIdentifier,
+        to: &LanguageIdentifier,
+    ) -> Result<String, Error>;
+}
+
+/// A [`TranslationProvider`] calling a [LibreTranslate](https://libretranslate.com)-style
+/// HTTP API: `POST {endpoint} {"q", "source", "target"}` → `{"translatedText"}`.
+pub struct LibreTranslateProvider {
+    endpoint: String,
+}
+
+impl LibreTranslateProvider {
+    /// Builds a provider that sends requests to `endpoint`.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+        }
+    }
+}
+
+impl TranslationProvider for LibreTranslateProvider {
+    fn translate(
+        &self,
+        text: &str,
+        from: &LanguageIdentifier,
+        to: &LanguageIdentifier,
+    ) -> Result<String, Error> {
+        let request_body = serde_json::json!({
+            "q": text,
+            "source": from.language.to_string(),
+            "target": to.language.to_string(),
+        });
+
+        let response: serde_json::Value = ureq::post(&self.endpoint)
+            .send_json(request_body)
+            .map_err(|source| Error::TranslationRequestFailed {
+                source: Box::new(source),
+            })?
+            .into_json()
+            .map_err(|source| Error::TranslationRequestFailed {
+                source: Box::new(source),
+            })?;
+
+        response["translatedText"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| Error::TranslationRequestFailed {
+                source: "response did not contain a `translatedText` field".into(),
+            })
+    }
+}
+
+/// The registered [`TranslationProvider`], set once via [`set_translation_provider`].
+static PROVIDER: OnceLock<Box<dyn TranslationProvider + Send + Sync>> = OnceLock::new();
+
+/// Translations already fetched, memoized by `(key, target locale)` so a successful
+/// translation is fetched only once per key per locale. Failed requests are not cached.
+static CACHE: LazyLock<Mutex<HashMap<(String, LanguageIdentifier), String>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Registers the [`TranslationProvider`] used by [`translate_missing`].
+///
+/// Only the first call takes effect, mirroring [`FALLBACK_LOCALE`](crate::locale::FALLBACK_LOCALE).
+pub fn set_translation_provider(provider: impl TranslationProvider + Send + Sync + 'static) {
+    let _ = PROVIDER.set(Box::new(provider));
+}
+
+/// Translates `text` (the base locale's string for `key`) into `to`, memoizing the result.
+///
+/// # Errors
+///
+/// Returns [`Error::TranslationProviderUnavailable`] if no provider has been registered via
+/// [`set_translation_provider`], or [`Error::TranslationRequestFailed`] if the underlying
+/// request fails.
+pub fn translate_missing(
+    key: &str,
+    text: &str,
+    from: &LanguageIdentifier,
+    to: &LanguageIdentifier,
+) -> Result<String, Error> {
+    let cache_key = (key.to_string(), to.clone());
+    if let Some(cached) = CACHE.lock().expect("translation cache lock poisoned").get(&cache_key) {
+        return Ok(cached.clone());
+    }
+
+    let provider = PROVIDER
+        .get()
+        .ok_or(Error::TranslationProviderUnavailable)?;
+    let translated = provider.translate(text, from, to)?;
+
+    CACHE
+        .lock()
+        .expect("translation cache lock poisoned")
+        .insert(cache_key, translated.clone());
+
+    Ok(translated)
+}
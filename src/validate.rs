@@ -0,0 +1,139 @@
+//! Diagnostics: missing-translation logging and bundle validation.
+//!
+//! [`validate_locales`] is meant for CI: run it against the locales directory and fail the
+//! build if it reports any [`MissingKey`] or [`LoadError`]. For runtime diagnostics, enable
+//! the `log-miss-tr` feature, which makes [`t!`](crate::t)/[`try_t!`](crate::try_t) log
+//! whenever a lookup falls through the entire fallback chain without finding a key.
+
+use std::{collections::HashSet, fs, path::Path};
+
+use fluent_templates::{
+    fluent_bundle::{ast::Entry, FluentResource},
+    LanguageIdentifier,
+};
+
+use crate::Error;
+
+/// A message identifier present in the `reference` locale but missing from `locale`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingKey {
+    /// The locale the key is missing from.
+    pub locale: LanguageIdentifier,
+    /// The message identifier that's missing.
+    pub key: String,
+}
+
+/// A `locale` whose bundle could not be loaded at all while validating, as opposed to
+/// loading fine but missing individual keys (see [`MissingKey`]).
+#[derive(Debug)]
+pub struct LoadError {
+    /// The locale whose directory could not be loaded.
+    pub locale: LanguageIdentifier,
+    /// The underlying error encountered while loading it.
+    pub source: Error,
+}
+
+/// The outcome of [`validate_locales`]: keys missing from non-reference locales, plus any
+/// locales that could not be loaded at all.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    /// Message identifiers present in `reference` but missing from some other locale.
+    pub missing: Vec<MissingKey>,
+    /// Locales that failed to load entirely, so they couldn't be checked for missing keys.
+    pub load_errors: Vec<LoadError>,
+}
+
+/// Loads the `.ftl` files of each locale in `locales` from `locales_dir` and reports, for
+/// each locale other than `reference`, which message identifiers present in `reference` are
+/// absent.
+///
+/// Only the locales listed in `locales` are checked; other locale directories under
+/// `locales_dir` are not discovered or reported.
+///
+/// # Errors
+///
+/// Returns [`Error::ReferenceLocaleUnavailable`] if `reference`'s own directory cannot be
+/// loaded; without a reference key set there's nothing to validate the others against. Any
+/// other locale that fails to load is instead collected into the returned
+/// [`ValidationReport::load_errors`], since a single broken locale shouldn't block
+/// validating the rest.
+pub fn validate_locales(
+    reference: &LanguageIdentifier,
+    locales: &[LanguageIdentifier],
+    locales_dir: &Path,
+) -> Result<ValidationReport, Error> {
+    let reference_keys =
+        message_ids_for(reference, locales_dir).map_err(|source| Error::ReferenceLocaleUnavailable {
+            locale: reference.clone(),
+            source: Box::new(source),
+        })?;
+
+    let mut report = ValidationReport::default();
+    for locale in locales {
+        if locale == reference {
+            continue;
+        }
+
+        let keys = match message_ids_for(locale, locales_dir) {
+            Ok(keys) => keys,
+            Err(source) => {
+                report.load_errors.push(LoadError {
+                    locale: locale.clone(),
+                    source,
+                });
+                continue;
+            }
+        };
+        report.missing.extend(reference_keys.difference(&keys).map(|key| MissingKey {
+            locale: locale.clone(),
+            key: key.clone(),
+        }));
+    }
+
+    Ok(report)
+}
+
+/// Parses every `.ftl` file under `locales_dir/<locale>` and collects the set of
+/// top-level message identifiers they define.
+fn message_ids_for(locale: &LanguageIdentifier, locales_dir: &Path) -> Result<HashSet<String>, Error> {
+    let dir = locales_dir.join(locale.to_string());
+    if !dir.is_dir() {
+        return Err(Error::MissingLocaleDir {
+            locale: locale.clone(),
+        });
+    }
+
+    let entries = fs::read_dir(&dir).map_err(|source| Error::ReadLocalesDir {
+        path: dir.clone(),
+        source,
+    })?;
+
+    let mut ids = HashSet::new();
+    for entry in entries {
+        let entry = entry.map_err(|source| Error::ReadLocalesDir {
+            path: dir.clone(),
+            source,
+        })?;
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("ftl") {
+            continue;
+        }
+
+        let contents = fs::read_to_string(&path).map_err(|source| Error::ReadLocalesDir {
+            path: path.clone(),
+            source,
+        })?;
+        let resource = FluentResource::try_new(contents).map_err(|(_, errors)| Error::ParseFtl {
+            path: path.clone(),
+            source: format!("{errors:?}"),
+        })?;
+
+        for entry in &resource.ast().body {
+            if let Entry::Message(message) = entry {
+                ids.insert(message.id.name.to_string());
+            }
+        }
+    }
+
+    Ok(ids)
+}
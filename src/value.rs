@@ -3,10 +3,13 @@
 use std::{
     borrow::Cow,
     path::{Path, PathBuf},
+    time::SystemTime,
 };
 
 /// Re-export the type.
 pub use fluent_templates::fluent_bundle::FluentValue;
+/// Re-export the trait used to implement locale-aware [`FluentValue::Custom`] types.
+pub use fluent_templates::fluent_bundle::types::FluentType;
 
 /// Helper trait for converting various types to a [`FluentValue`].
 ///
@@ -91,6 +94,224 @@ impl_fluent_for!(
     &'static str
 );
 
+/// A locale-aware number, optionally rendered as a currency amount.
+///
+/// Build one with [`money`] for currency amounts, or construct directly for plain
+/// grouped numbers. Unlike [`fluent_bundle`]'s built-in `FluentNumber`, [`as_string`]
+/// places the grouping separator, decimal separator and currency symbol according to
+/// [`get_locale`](crate::get_locale) rather than always using `en-US` conventions.
+///
+/// [`as_string`]: FluentType::as_string
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalizedNumber {
+    value: f64,
+    min_fraction_digits: usize,
+    currency: Option<&'static str>,
+}
+
+impl LocalizedNumber {
+    /// Formats `value` using the given minimum number of fraction digits.
+    pub fn new(value: f64, min_fraction_digits: usize) -> Self {
+        Self {
+            value,
+            min_fraction_digits,
+            currency: None,
+        }
+    }
+}
+
+/// Builds a [`LocalizedNumber`] rendered as a currency amount, e.g. `t!("invoice", { "total" =>
+/// money(1234.5, "EUR") })` yields `1.234,50 €` in `de` and `€1,234.50` in `en`.
+pub fn money(value: f64, currency: &'static str) -> LocalizedNumber {
+    LocalizedNumber {
+        value,
+        min_fraction_digits: 2,
+        currency: Some(currency),
+    }
+}
+
+impl FluentType for LocalizedNumber {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, _intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        Cow::Owned(self.format())
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        _intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        Cow::Owned(self.format())
+    }
+}
+
+impl LocalizedNumber {
+    /// Renders the number according to the grouping/decimal conventions of the current
+    /// locale's base language, placing the currency symbol (if any) accordingly.
+    fn format(&self) -> String {
+        let (grouping_sep, decimal_sep) = match crate::get_locale().language.as_str() {
+            "de" | "es" | "it" => (".", ","),
+            // French groups with a narrow no-break space, not a dot.
+            "fr" => ("\u{202F}", ","),
+            _ => (",", "."),
+        };
+
+        let formatted = format!("{:.*}", self.min_fraction_digits, self.value.abs());
+        let (integer_part, fraction_part) = formatted
+            .split_once('.')
+            .unwrap_or((formatted.as_str(), ""));
+
+        let mut grouped = String::new();
+        for (i, digit) in integer_part.chars().rev().enumerate() {
+            if i > 0 && i % 3 == 0 {
+                grouped.push(grouping_sep.chars().next().unwrap_or(','));
+            }
+            grouped.push(digit);
+        }
+        let integer_part: String = grouped.chars().rev().collect();
+
+        let sign = if self.value.is_sign_negative() { "-" } else { "" };
+        let number = if fraction_part.is_empty() {
+            format!("{sign}{integer_part}")
+        } else {
+            format!("{sign}{integer_part}{decimal_sep}{fraction_part}")
+        };
+
+        match self.currency {
+            // English-style conventions prefix the currency symbol; most others suffix it.
+            Some(currency) if grouping_sep == "," => format!("{}{number}", currency_symbol(currency)),
+            Some(currency) => format!("{number} {}", currency_symbol(currency)),
+            None => number,
+        }
+    }
+}
+
+/// Maps a small set of ISO 4217 currency codes to their symbol.
+///
+/// Falls back to the code itself for currencies we don't special-case.
+fn currency_symbol(currency: &str) -> &str {
+    match currency {
+        "EUR" => "€",
+        "USD" => "$",
+        "GBP" => "£",
+        "JPY" => "¥",
+        other => other,
+    }
+}
+
+/// A locale-aware date/time value, rendered according to the active locale's conventions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalizedDateTime {
+    timestamp: SystemTime,
+}
+
+impl LocalizedDateTime {
+    /// Wraps a [`SystemTime`] for locale-aware formatting.
+    pub fn new(timestamp: SystemTime) -> Self {
+        Self { timestamp }
+    }
+}
+
+/// Builds a [`LocalizedDateTime`] from any type that converts to [`SystemTime`], e.g.
+/// `t!("invoice-date", { "date" => date(SystemTime::now()) })`.
+///
+/// This also accepts a [`chrono::DateTime<Utc>`](chrono::DateTime) (behind the `chrono`
+/// feature) or a [`time::OffsetDateTime`](time::OffsetDateTime) (behind the `time`
+/// feature), since both convert to [`SystemTime`] via their own `Into` implementations.
+pub fn date(timestamp: impl Into<SystemTime>) -> LocalizedDateTime {
+    LocalizedDateTime::new(timestamp.into())
+}
+
+/// Convenience [`From`] impl so a [`chrono::DateTime<Utc>`](chrono::DateTime) can be
+/// passed directly wherever a [`LocalizedDateTime`] is expected, e.g. `.into()` at a call
+/// site that doesn't want to go through [`date`]. Requires the `chrono` feature.
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for LocalizedDateTime {
+    fn from(timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        Self::new(timestamp.into())
+    }
+}
+
+/// Convenience [`From`] impl so a [`time::OffsetDateTime`] can be passed directly
+/// wherever a [`LocalizedDateTime`] is expected. Requires the `time` feature.
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for LocalizedDateTime {
+    fn from(timestamp: time::OffsetDateTime) -> Self {
+        Self::new(timestamp.into())
+    }
+}
+
+impl FluentType for LocalizedDateTime {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(&self, _intls: &intl_memoizer::IntlLangMemoizer) -> Cow<'static, str> {
+        Cow::Owned(self.format())
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        _intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        Cow::Owned(self.format())
+    }
+}
+
+impl LocalizedDateTime {
+    /// Renders the date as `YYYY-MM-DD`, `MM/DD/YYYY`, `DD.MM.YYYY` or `DD/MM/YYYY`
+    /// depending on the current locale's base language.
+    fn format(&self) -> String {
+        let since_epoch = self
+            .timestamp
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        let days_since_epoch = since_epoch.as_secs() / 86_400;
+        let (year, month, day) = civil_from_days(days_since_epoch as i64);
+
+        match crate::get_locale().language.as_str() {
+            "en" => format!("{month:02}/{day:02}/{year:04}"),
+            "de" => format!("{day:02}.{month:02}.{year:04}"),
+            // fr/es/it separate day/month/year with a slash, not a dot.
+            "fr" | "es" | "it" => format!("{day:02}/{month:02}/{year:04}"),
+            _ => format!("{year:04}-{month:02}-{day:02}"),
+        }
+    }
+}
+
+/// Converts a day count since the Unix epoch into a `(year, month, day)` triple,
+/// using Howard Hinnant's well-known `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { y + 1 } else { y };
+
+    (year, month, day)
+}
+
+/// [`ToFluentValue`] impl producing a [`FluentValue::Custom`] for locale-aware numbers.
+impl ToFluentValue for LocalizedNumber {
+    fn to_fluent_value(&self) -> FluentValue<'static> {
+        FluentValue::Custom(Box::new(self.clone()))
+    }
+}
+
+/// [`ToFluentValue`] impl producing a [`FluentValue::Custom`] for locale-aware dates.
+impl ToFluentValue for LocalizedDateTime {
+    fn to_fluent_value(&self) -> FluentValue<'static> {
+        FluentValue::Custom(Box::new(self.clone()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use testresult::TestResult;
@@ -113,4 +334,70 @@ mod tests {
 
         Ok(())
     }
+
+    /// Asserts that [`money`] renders grouping, decimal separator and currency symbol
+    /// placement according to the active locale.
+    #[test]
+    fn test_money_formatting_per_locale() -> TestResult<()> {
+        use super::money;
+
+        set_locale(Some("en-US"))?;
+        assert_eq!(
+            t!("invoice", { "total" => money(1234.5, "EUR") }),
+            "Total: €1,234.50"
+        );
+
+        set_locale(Some("de-DE"))?;
+        assert_eq!(
+            t!("invoice", { "total" => money(1234.5, "EUR") }),
+            "Total: 1.234,50 €"
+        );
+
+        // French groups with a narrow no-break space (U+202F), not a dot like German.
+        set_locale(Some("fr-FR"))?;
+        assert_eq!(
+            t!("invoice", { "total" => money(1234.5, "EUR") }),
+            "Total: 1\u{202F}234,50 €"
+        );
+
+        Ok(())
+    }
+
+    /// Asserts that [`date`] renders the day/month/year order and separator according to
+    /// the active locale.
+    #[test]
+    fn test_date_formatting_per_locale() -> TestResult<()> {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        use super::date;
+
+        // 2024-03-05, 00:00:00 UTC.
+        let timestamp = UNIX_EPOCH + Duration::from_secs(1_709_596_800);
+
+        set_locale(Some("en-US"))?;
+        assert_eq!(
+            t!("invoice-date", { "date" => date(timestamp) }),
+            "Date: 03/05/2024"
+        );
+
+        set_locale(Some("de-DE"))?;
+        assert_eq!(
+            t!("invoice-date", { "date" => date(timestamp) }),
+            "Date: 05.03.2024"
+        );
+
+        set_locale(Some("fr-FR"))?;
+        assert_eq!(
+            t!("invoice-date", { "date" => date(timestamp) }),
+            "Date: 05/03/2024"
+        );
+
+        set_locale(Some("ja-JP"))?;
+        assert_eq!(
+            t!("invoice-date", { "date" => date(timestamp) }),
+            "Date: 2024-03-05"
+        );
+
+        Ok(())
+    }
 }